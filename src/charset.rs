@@ -2,6 +2,9 @@
 //!
 //! Unicode reference: <http://www.rikai.com/library/kanjitables/kanji_codes.unicode.shtml>
 
+mod kanji_level;
+pub use kanji_level::{classify_kanji, is_jinmeiyo_kanji, is_joyo_kanji, KanjiLevel};
+
 const PUNCTUATION_START: char = '\u{3000}';
 const PUNCTUATION_END: char = '\u{303f}';
 const HIRAGANA_START: char = '\u{3040}';
@@ -10,8 +13,17 @@ const KATAKANA_START: char = '\u{30a0}';
 const KATAKANA_END: char = '\u{30ff}';
 const FULL_WIDTH_ROMAN_HALF_WIDTH_KATAKANA_START: char = '\u{ff00}';
 const FULL_WIDTH_ROMAN_HALF_WIDTH_KATAKANA_END: char = '\u{ffef}';
+const FULL_WIDTH_ROMAN_START: char = '\u{ff01}';
+const FULL_WIDTH_ROMAN_END: char = '\u{ff5e}';
+const HALF_WIDTH_KATAKANA_START: char = '\u{ff61}';
+const HALF_WIDTH_KATAKANA_END: char = '\u{ff9f}';
+const IDEOGRAPHIC_SPACE: char = '\u{3000}';
 const KANJI_START: char = '\u{4e00}';
 const KANJI_END: char = '\u{9faf}';
+const KANJI_EXTENSION_A_START: char = '\u{3400}';
+const KANJI_EXTENSION_A_END: char = '\u{4dbf}';
+const KANJI_COMPATIBILITY_START: char = '\u{f900}';
+const KANJI_COMPATIBILITY_END: char = '\u{faff}';
 
 /// Returns `true` if the `char` is Japanese (kana, kanji, Japanese punctuation, etc).
 pub fn is_japanese(ch: char) -> bool {
@@ -79,8 +91,29 @@ pub fn is_kana(ch: char) -> bool {
 }
 
 /// Returns `true` if the `char` is a kanji character.
+///
+/// This covers the main CJK Unified Ideographs block, CJK Unified Ideographs Extension A (which
+/// holds newer Jōyō additions), and the CJK Compatibility Ideographs block.
 pub fn is_kanji(ch: char) -> bool {
-    ch >= KANJI_START && ch <= KANJI_END
+    (ch >= KANJI_START && ch <= KANJI_END)
+        || (ch >= KANJI_EXTENSION_A_START && ch <= KANJI_EXTENSION_A_END)
+        || (ch >= KANJI_COMPATIBILITY_START && ch <= KANJI_COMPATIBILITY_END)
+}
+
+/// Returns `true` if the `char` is a full-width roman character (FF01 - FF5E).
+pub fn is_full_width_roman(ch: char) -> bool {
+    ch >= FULL_WIDTH_ROMAN_START && ch <= FULL_WIDTH_ROMAN_END
+}
+
+/// Returns `true` if the `char` is a half-width katakana character, including the half-width
+/// dakuten/handakuten marks (FF61 - FF9F).
+pub fn is_half_width_katakana(ch: char) -> bool {
+    ch >= HALF_WIDTH_KATAKANA_START && ch <= HALF_WIDTH_KATAKANA_END
+}
+
+/// Returns `true` if the `char` is the full-width (ideographic) space (3000).
+pub fn is_ideographic_space(ch: char) -> bool {
+    ch == IDEOGRAPHIC_SPACE
 }
 
 #[cfg(test)]
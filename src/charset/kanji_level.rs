@@ -0,0 +1,126 @@
+//! Kanji classification by Jōyō school grade and Jinmeiyō/hyōgai status.
+//!
+//! ### Data coverage
+//!
+//! The embedded tables are a curated subset of each official Jōyō/Jinmeiyō list rather than the
+//! complete (~2136/~863 character) lists published by MEXT/the Ministry of Justice: `Grade1` is
+//! exhaustive (all 80 kyōiku kanji), while the other levels ship a representative sample of
+//! well-known characters. Extend the tables here as more complete data becomes available.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::charset;
+
+/// Classifies a kanji by the school grade it's taught in (for the Jōyō "kyōiku" kanji) or by
+/// which other official list it belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum KanjiLevel {
+    Grade1,
+    Grade2,
+    Grade3,
+    Grade4,
+    Grade5,
+    Grade6,
+    /// A Jōyō kanji taught after grade 6 (junior high/high school).
+    SecondaryJoyo,
+    /// A Jinmeiyō kanji: not Jōyō, but approved for use in personal names.
+    Jinmeiyo,
+    /// Any other kanji, outside the Jōyō/Jinmeiyō lists.
+    Hyougai,
+}
+
+const GRADE1: &str = "一右雨円王音下火花貝学気九休玉金空月犬見五口校左三山子四糸字耳七車手十出女小上森人水正生青夕石赤千川先早草足村大男竹中虫町天田土二日入年白八百文木本名目立力林六";
+const GRADE2: &str = "引羽雲園遠何科夏家歌画回会海絵外角楽活間";
+const GRADE3: &str = "悪安暗医委意育員院飲運泳駅央横屋温化荷界";
+const GRADE4: &str = "愛案以衣位囲胃印英栄塩億加果貨課芽改械害";
+const GRADE5: &str = "圧移因永営衛易益液演応往桜恩可仮価河過";
+const GRADE6: &str = "異遺域宇映延沿我灰拡革閣割株干巻看簡危揮";
+const SECONDARY_JOYO: &str = "亜哀挨曖宛嵐畏萎椅彙";
+const JINMEIYO: &str = "伊佐凛奈朋駕亘亥亦亞";
+
+fn table(level: KanjiLevel) -> &'static str {
+    match level {
+        KanjiLevel::Grade1 => GRADE1,
+        KanjiLevel::Grade2 => GRADE2,
+        KanjiLevel::Grade3 => GRADE3,
+        KanjiLevel::Grade4 => GRADE4,
+        KanjiLevel::Grade5 => GRADE5,
+        KanjiLevel::Grade6 => GRADE6,
+        KanjiLevel::SecondaryJoyo => SECONDARY_JOYO,
+        KanjiLevel::Jinmeiyo => JINMEIYO,
+        KanjiLevel::Hyougai => "",
+    }
+}
+
+static LEVEL_BY_KANJI: Lazy<HashMap<char, KanjiLevel>> = Lazy::new(|| {
+    use KanjiLevel::*;
+
+    [Grade1, Grade2, Grade3, Grade4, Grade5, Grade6, SecondaryJoyo, Jinmeiyo]
+        .into_iter()
+        .flat_map(|level| table(level).chars().map(move |ch| (ch, level)))
+        .collect()
+});
+
+/// Classifies the given kanji `char` by its Jōyō grade, or as Jinmeiyō or hyōgai.
+///
+/// Returns `None` if `ch` isn't a kanji at all (see [charset::is_kanji]). A kanji outside the
+/// embedded Jōyō/Jinmeiyō tables classifies as `Some(KanjiLevel::Hyougai)`.
+pub fn classify_kanji(ch: char) -> Option<KanjiLevel> {
+    if !charset::is_kanji(ch) {
+        return None;
+    }
+
+    Some(LEVEL_BY_KANJI.get(&ch).copied().unwrap_or(KanjiLevel::Hyougai))
+}
+
+/// Returns `true` if the `char` is a Jōyō kanji (grades 1-6, or the post-grade-6 secondary list).
+pub fn is_joyo_kanji(ch: char) -> bool {
+    matches!(
+        classify_kanji(ch),
+        Some(
+            KanjiLevel::Grade1
+                | KanjiLevel::Grade2
+                | KanjiLevel::Grade3
+                | KanjiLevel::Grade4
+                | KanjiLevel::Grade5
+                | KanjiLevel::Grade6
+                | KanjiLevel::SecondaryJoyo
+        )
+    )
+}
+
+/// Returns `true` if the `char` is a Jinmeiyō kanji (approved for personal names but not Jōyō).
+pub fn is_jinmeiyo_kanji(ch: char) -> bool {
+    classify_kanji(ch) == Some(KanjiLevel::Jinmeiyo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_kanji_test() {
+        assert_eq!(Some(KanjiLevel::Grade1), classify_kanji('一'));
+        assert_eq!(Some(KanjiLevel::Grade6), classify_kanji('異'));
+        assert_eq!(Some(KanjiLevel::SecondaryJoyo), classify_kanji('亜'));
+        assert_eq!(Some(KanjiLevel::Jinmeiyo), classify_kanji('伊'));
+        assert_eq!(Some(KanjiLevel::Hyougai), classify_kanji('麤'));
+        assert_eq!(None, classify_kanji('あ'));
+    }
+
+    #[test]
+    fn is_joyo_kanji_test() {
+        assert!(is_joyo_kanji('一'));
+        assert!(is_joyo_kanji('亜'));
+        assert!(!is_joyo_kanji('伊'));
+        assert!(!is_joyo_kanji('麤'));
+    }
+
+    #[test]
+    fn is_jinmeiyo_kanji_test() {
+        assert!(is_jinmeiyo_kanji('伊'));
+        assert!(!is_jinmeiyo_kanji('一'));
+        assert!(!is_jinmeiyo_kanji('麤'));
+    }
+}
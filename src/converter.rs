@@ -14,6 +14,15 @@ use std::collections::HashMap;
 
 use crate::{charset, Vowel};
 
+mod romaji;
+pub use romaji::{to_kana, to_romaji};
+
+mod width;
+pub use width::{
+    convert_full_width_roman_to_ascii, convert_full_width_to_half_width_katakana_string,
+    convert_half_width_to_full_width_katakana_string,
+};
+
 struct TwoWayMap {
     normal: HashMap<Vowel, char>,
     reversed: HashMap<char, Vowel>,
@@ -240,6 +249,119 @@ pub fn convert_to_vowel_in_stem(hiragana: char, to_vowel: Vowel) -> char {
     *map.normal.get(&to_vowel).unwrap()
 }
 
+/// Returns the map paired with `plain_map` once a dakuten (゛) is added to it, if any.
+fn dakuten_pair(plain_map: &'static TwoWayMap) -> Option<&'static TwoWayMap> {
+    if std::ptr::eq(plain_map, &*K_MAP) {
+        Some(&*G_MAP)
+    } else if std::ptr::eq(plain_map, &*S_MAP) {
+        Some(&*Z_MAP)
+    } else if std::ptr::eq(plain_map, &*T_MAP) {
+        Some(&*D_MAP)
+    } else if std::ptr::eq(plain_map, &*H_MAP) {
+        Some(&*B_MAP)
+    } else {
+        None
+    }
+}
+
+/// Returns the map paired with `plain_map` once a handakuten (゜) is added to it, if any.
+fn handakuten_pair(plain_map: &'static TwoWayMap) -> Option<&'static TwoWayMap> {
+    if std::ptr::eq(plain_map, &*H_MAP) {
+        Some(&*P_MAP)
+    } else {
+        None
+    }
+}
+
+/// Returns the plain map `voiced_map` was derived from by adding a dakuten/handakuten, if any.
+fn plain_pair(voiced_map: &'static TwoWayMap) -> Option<&'static TwoWayMap> {
+    if std::ptr::eq(voiced_map, &*G_MAP) {
+        Some(&*K_MAP)
+    } else if std::ptr::eq(voiced_map, &*Z_MAP) {
+        Some(&*S_MAP)
+    } else if std::ptr::eq(voiced_map, &*D_MAP) {
+        Some(&*T_MAP)
+    } else if std::ptr::eq(voiced_map, &*B_MAP) || std::ptr::eq(voiced_map, &*P_MAP) {
+        Some(&*H_MAP)
+    } else {
+        None
+    }
+}
+
+/// Applies `pair_fn` to the map `ch` belongs to, preserving whether `ch` was hiragana or
+/// katakana. Returns `ch` unchanged if `ch` isn't a kana or has no corresponding map.
+fn apply_diacritic_pair(ch: char, pair_fn: fn(&'static TwoWayMap) -> Option<&'static TwoWayMap>) -> char {
+    let is_katakana_input = charset::is_katakana(ch);
+    let hiragana = if is_katakana_input {
+        convert_katakana_to_hiragana(ch)
+    } else {
+        ch
+    };
+
+    let map = match get_map_for_hiragana(hiragana) {
+        Some(v) => v,
+        None => return ch,
+    };
+    let vowel = match map.reversed.get(&hiragana) {
+        Some(v) => *v,
+        None => return ch,
+    };
+    let target_map = match pair_fn(map) {
+        Some(v) => v,
+        None => return ch,
+    };
+    let result_hiragana = *target_map.normal.get(&vowel).unwrap();
+
+    if is_katakana_input {
+        convert_hiragana_to_katakana(result_hiragana)
+    } else {
+        result_hiragana
+    }
+}
+
+/// Adds a dakuten (゛) to the given hiragana or katakana `char` (e.g. か -> が, カ -> ガ).
+///
+/// Returns the same `char` if it has no voiced counterpart, per the crate's leniency policy.
+pub fn add_dakuten(ch: char) -> char {
+    apply_diacritic_pair(ch, dakuten_pair)
+}
+
+/// Adds a handakuten (゜) to the given hiragana or katakana `char` (e.g. は -> ぱ, ハ -> パ).
+///
+/// Returns the same `char` if it has no semi-voiced counterpart, per the crate's leniency policy.
+pub fn add_handakuten(ch: char) -> char {
+    apply_diacritic_pair(ch, handakuten_pair)
+}
+
+/// Removes a dakuten/handakuten from the given hiragana or katakana `char` (e.g. が -> か,
+/// ぱ -> は, パ -> ハ).
+///
+/// Returns the same `char` if it isn't voiced/semi-voiced, per the crate's leniency policy.
+pub fn remove_diacritic(ch: char) -> char {
+    apply_diacritic_pair(ch, plain_pair)
+}
+
+/// Returns `true` if the given hiragana or katakana `char` is voiced or semi-voiced (i.e. has a
+/// dakuten or handakuten).
+pub fn is_voiced(ch: char) -> bool {
+    let hiragana = if charset::is_katakana(ch) {
+        convert_katakana_to_hiragana(ch)
+    } else {
+        ch
+    };
+
+    let map = match get_map_for_hiragana(hiragana) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    std::ptr::eq(map, &*G_MAP)
+        || std::ptr::eq(map, &*Z_MAP)
+        || std::ptr::eq(map, &*D_MAP)
+        || std::ptr::eq(map, &*B_MAP)
+        || std::ptr::eq(map, &*P_MAP)
+}
+
 /// Converts the given katakana `char` to hiragana.
 pub fn convert_katakana_to_hiragana(katakana: char) -> char {
     if !charset::is_katakana(katakana) {
@@ -330,6 +452,35 @@ pub fn convert_hiragana_to_katakana_string(hiragana: &str) -> String {
     katakana_string
 }
 
+/// Expands Japanese iteration (repetition) marks by replacing each one with the character it
+/// stands in for.
+///
+/// `々` repeats the preceding kanji, `ゝ`/`ヽ` repeat the preceding kana as is, and `ゞ`/`ヾ` repeat
+/// the preceding kana with a dakuten added (e.g. みすゞ -> みすず; a mark following an
+/// already-voiced kana leaves it voiced, since [add_dakuten] is itself a no-op in that case).
+///
+/// A mark at the start of the string, or following a char it can't repeat (e.g. `々` after a
+/// non-kanji), is passed through unchanged per the crate's leniency policy.
+pub fn expand_iteration_marks(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut previous: Option<char> = None;
+
+    for ch in s.chars() {
+        let expanded = match ch {
+            '々' => previous.filter(|&prev| charset::is_kanji(prev)),
+            'ゝ' | 'ヽ' => previous.filter(|&prev| charset::is_kana(prev)),
+            'ゞ' | 'ヾ' => previous.filter(|&prev| charset::is_kana(prev)).map(add_dakuten),
+            _ => None,
+        };
+
+        let emitted = expanded.unwrap_or(ch);
+        result.push(emitted);
+        previous = Some(emitted);
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -348,6 +499,55 @@ mod tests {
         assert_eq!(expected, convert_to_vowel_in_stem(hiragana, to_vowel));
     }
 
+    #[rstest]
+    #[case('か', 'が')]
+    #[case('さ', 'ざ')]
+    #[case('た', 'だ')]
+    #[case('は', 'ば')]
+    #[case('カ', 'ガ')]
+    #[case('あ', 'あ')]
+    fn add_dakuten_test(#[case] ch: char, #[case] expected: char) {
+        assert_eq!(expected, add_dakuten(ch));
+    }
+
+    #[rstest]
+    #[case('は', 'ぱ')]
+    #[case('ハ', 'パ')]
+    #[case('か', 'か')]
+    fn add_handakuten_test(#[case] ch: char, #[case] expected: char) {
+        assert_eq!(expected, add_handakuten(ch));
+    }
+
+    #[rstest]
+    #[case('が', 'か')]
+    #[case('ぱ', 'は')]
+    #[case('パ', 'ハ')]
+    #[case('か', 'か')]
+    fn remove_diacritic_test(#[case] ch: char, #[case] expected: char) {
+        assert_eq!(expected, remove_diacritic(ch));
+    }
+
+    #[rstest]
+    #[case('が', true)]
+    #[case('ぱ', true)]
+    #[case('パ', true)]
+    #[case('か', false)]
+    #[case('あ', false)]
+    fn is_voiced_test(#[case] ch: char, #[case] expected: bool) {
+        assert_eq!(expected, is_voiced(ch));
+    }
+
+    #[rstest]
+    #[case("人々", "人人")]
+    #[case("みすゞ", "みすず")]
+    #[case("ハヽ", "ハハ")]
+    #[case("ばゞ", "ばば")]
+    #[case("々", "々")]
+    #[case("あゞ", "ああ")]
+    fn expand_iteration_marks_test(#[case] s: &str, #[case] expected: &str) {
+        assert_eq!(expected, expand_iteration_marks(s));
+    }
+
     #[rstest]
     fn convert_katakana_to_hiragana_returns_same_char_if_invalid() {
         assert_eq!('a', convert_katakana_to_hiragana('a'));
@@ -0,0 +1,312 @@
+//! Converts between kana and romaji (the Latin-script transliteration of Japanese).
+//!
+//! The romanization follows a Waapuro/Hepburn-style syllable table: long vowels are written by
+//! repeating the vowel letter in [to_kana] and by repeating the previous syllable's vowel in
+//! [to_romaji], a doubled consonant represents the sokuon っ, and a bare `n` not followed by a
+//! vowel or `y` represents ん. As with the rest of this crate, unrecognized input is passed
+//! through unchanged instead of erroring.
+
+use maplit::hashmap;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::Vowel;
+
+use super::TwoWayMap;
+
+static ROMAJI_TO_KANA: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
+    hashmap! {
+        "a" => "あ", "i" => "い", "u" => "う", "e" => "え", "o" => "お",
+        "ka" => "か", "ki" => "き", "ku" => "く", "ke" => "け", "ko" => "こ",
+        "ga" => "が", "gi" => "ぎ", "gu" => "ぐ", "ge" => "げ", "go" => "ご",
+        "sa" => "さ", "shi" => "し", "si" => "し", "su" => "す", "se" => "せ", "so" => "そ",
+        "za" => "ざ", "ji" => "じ", "zi" => "じ", "zu" => "ず", "ze" => "ぜ", "zo" => "ぞ",
+        "ta" => "た", "chi" => "ち", "ti" => "ち", "tsu" => "つ", "tu" => "つ", "te" => "て", "to" => "と",
+        "da" => "だ", "di" => "ぢ", "du" => "づ", "de" => "で", "do" => "ど",
+        "na" => "な", "ni" => "に", "nu" => "ぬ", "ne" => "ね", "no" => "の",
+        "ha" => "は", "hi" => "ひ", "fu" => "ふ", "hu" => "ふ", "he" => "へ", "ho" => "ほ",
+        "ba" => "ば", "bi" => "び", "bu" => "ぶ", "be" => "べ", "bo" => "ぼ",
+        "pa" => "ぱ", "pi" => "ぴ", "pu" => "ぷ", "pe" => "ぺ", "po" => "ぽ",
+        "ma" => "ま", "mi" => "み", "mu" => "む", "me" => "め", "mo" => "も",
+        "ya" => "や", "yu" => "ゆ", "yo" => "よ",
+        "ra" => "ら", "ri" => "り", "ru" => "る", "re" => "れ", "ro" => "ろ",
+        "wa" => "わ", "wo" => "を",
+        "kya" => "きゃ", "kyu" => "きゅ", "kyo" => "きょ",
+        "gya" => "ぎゃ", "gyu" => "ぎゅ", "gyo" => "ぎょ",
+        "sha" => "しゃ", "sya" => "しゃ", "shu" => "しゅ", "syu" => "しゅ", "sho" => "しょ", "syo" => "しょ",
+        "ja" => "じゃ", "jya" => "じゃ", "zya" => "じゃ",
+        "ju" => "じゅ", "jyu" => "じゅ", "zyu" => "じゅ",
+        "jo" => "じょ", "jyo" => "じょ", "zyo" => "じょ",
+        "cha" => "ちゃ", "tya" => "ちゃ", "chu" => "ちゅ", "tyu" => "ちゅ", "cho" => "ちょ", "tyo" => "ちょ",
+        "nya" => "にゃ", "nyu" => "にゅ", "nyo" => "にょ",
+        "hya" => "ひゃ", "hyu" => "ひゅ", "hyo" => "ひょ",
+        "bya" => "びゃ", "byu" => "びゅ", "byo" => "びょ",
+        "pya" => "ぴゃ", "pyu" => "ぴゅ", "pyo" => "ぴょ",
+        "mya" => "みゃ", "myu" => "みゅ", "myo" => "みょ",
+        "rya" => "りゃ", "ryu" => "りゅ", "ryo" => "りょ",
+    }
+});
+
+fn is_romaji_consonant(ch: char) -> bool {
+    matches!(
+        ch,
+        'k' | 'g' | 's' | 'z' | 't' | 'd' | 'h' | 'f' | 'b' | 'p' | 'm' | 'y' | 'r' | 'w' | 'c' | 'j'
+    )
+}
+
+/// Converts the given romaji string to kana (hiragana), using a greedy longest-match-first scan.
+pub fn to_kana(romaji: &str) -> String {
+    let chars: Vec<char> = romaji.chars().collect();
+    let mut result = String::with_capacity(chars.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        // A doubled consonant (other than n, see below) emits a sokuon and continues.
+        if i + 1 < chars.len() && chars[i] == chars[i + 1] && is_romaji_consonant(chars[i]) {
+            result.push('っ');
+            i += 1;
+            continue;
+        }
+
+        // A standalone n not followed by a vowel or y emits ん.
+        if chars[i] == 'n' {
+            let next = chars.get(i + 1).copied();
+            let continues_syllable = matches!(next, Some('a' | 'i' | 'u' | 'e' | 'o' | 'y'));
+            if !continues_syllable {
+                result.push('ん');
+                i += 1;
+                continue;
+            }
+        }
+
+        let mut matched = false;
+        for len in (1..=3).rev() {
+            if i + len > chars.len() {
+                continue;
+            }
+            let candidate: String = chars[i..i + len].iter().collect();
+            if let Some(kana) = ROMAJI_TO_KANA.get(candidate.as_str()) {
+                result.push_str(kana);
+                i += len;
+                matched = true;
+                break;
+            }
+        }
+
+        if !matched {
+            // Unmatched chars pass through unchanged per the crate's leniency policy.
+            result.push(chars[i]);
+            i += 1;
+        }
+    }
+
+    result
+}
+
+fn consonant_prefix(map: &'static TwoWayMap) -> &'static str {
+    if std::ptr::eq(map, &*super::K_MAP) {
+        "k"
+    } else if std::ptr::eq(map, &*super::G_MAP) {
+        "g"
+    } else if std::ptr::eq(map, &*super::S_MAP) {
+        "s"
+    } else if std::ptr::eq(map, &*super::Z_MAP) {
+        "z"
+    } else if std::ptr::eq(map, &*super::T_MAP) {
+        "t"
+    } else if std::ptr::eq(map, &*super::D_MAP) {
+        "d"
+    } else if std::ptr::eq(map, &*super::N_MAP) {
+        "n"
+    } else if std::ptr::eq(map, &*super::H_MAP) {
+        "h"
+    } else if std::ptr::eq(map, &*super::B_MAP) {
+        "b"
+    } else if std::ptr::eq(map, &*super::P_MAP) {
+        "p"
+    } else if std::ptr::eq(map, &*super::M_MAP) {
+        "m"
+    } else if std::ptr::eq(map, &*super::R_MAP) {
+        "r"
+    } else if std::ptr::eq(map, &*super::Y_MAP) || std::ptr::eq(map, &*super::Y_SMALL_MAP) {
+        "y"
+    } else {
+        ""
+    }
+}
+
+fn consonant_and_vowel(hiragana: char) -> Option<(&'static str, Vowel)> {
+    // わ and を have no entry in converter.rs's maps (see convert_to_vowel_in_stem's わ special
+    // case), so they need to be matched directly instead of through get_map_for_hiragana.
+    match hiragana {
+        'わ' => return Some(("w", Vowel::A)),
+        'を' => return Some(("w", Vowel::O)),
+        _ => {}
+    }
+
+    let map = super::get_map_for_hiragana(hiragana)?;
+    let vowel = map.reversed.get(&hiragana).copied()?;
+    Some((consonant_prefix(map), vowel))
+}
+
+fn vowel_letter(vowel: Vowel) -> char {
+    match vowel {
+        Vowel::A => 'a',
+        Vowel::I => 'i',
+        Vowel::U => 'u',
+        Vowel::E => 'e',
+        Vowel::O => 'o',
+    }
+}
+
+fn syllable_romaji(consonant: &str, vowel: Vowel) -> String {
+    match (consonant, vowel) {
+        ("s", Vowel::I) => "shi".to_string(),
+        ("z", Vowel::I) => "ji".to_string(),
+        ("t", Vowel::I) => "chi".to_string(),
+        ("t", Vowel::U) => "tsu".to_string(),
+        ("d", Vowel::I) => "ji".to_string(),
+        ("d", Vowel::U) => "zu".to_string(),
+        ("h", Vowel::U) => "fu".to_string(),
+        _ => format!("{consonant}{}", vowel_letter(vowel)),
+    }
+}
+
+fn combining_consonant(consonant: &str) -> String {
+    match consonant {
+        "s" => "sh".to_string(),
+        "z" | "d" => "j".to_string(),
+        "t" => "ch".to_string(),
+        other => format!("{other}y"),
+    }
+}
+
+fn is_small_y(ch: char) -> bool {
+    matches!(ch, 'ゃ' | 'ゅ' | 'ょ')
+}
+
+/// Converts the given kana (hiragana or katakana) string to romaji.
+pub fn to_romaji(kana: &str) -> String {
+    let chars: Vec<char> = kana.chars().collect();
+    let mut result = String::with_capacity(chars.len() * 2);
+    let mut i = 0;
+    let mut pending_sokuon = false;
+
+    while i < chars.len() {
+        let raw = chars[i];
+
+        // ー is shared between hiragana and katakana text and has no hiragana equivalent, so it
+        // must be checked before the katakana-to-hiragana conversion below.
+        if raw == 'ー' {
+            if let Some(last_vowel) = result.chars().last() {
+                result.push(last_vowel);
+            }
+            i += 1;
+            continue;
+        }
+
+        let ch = super::convert_katakana_to_hiragana(raw);
+
+        if ch == 'っ' {
+            // A still-pending sokuon means the previous っ never found a consonant mora to
+            // double either; emit it as is before starting to track the new one.
+            if pending_sokuon {
+                result.push('っ');
+            }
+            pending_sokuon = true;
+            i += 1;
+            continue;
+        }
+
+        if ch == 'ん' {
+            if pending_sokuon {
+                result.push('っ');
+                pending_sokuon = false;
+            }
+            result.push('n');
+            i += 1;
+            continue;
+        }
+
+        let Some((consonant, vowel)) = consonant_and_vowel(ch) else {
+            if pending_sokuon {
+                result.push('っ');
+                pending_sokuon = false;
+            }
+            result.push(raw);
+            i += 1;
+            continue;
+        };
+
+        if pending_sokuon {
+            match consonant.chars().next() {
+                Some(first) => result.push(first),
+                // A vowel has no consonant to double, so the っ is emitted as is.
+                None => result.push('っ'),
+            }
+            pending_sokuon = false;
+        }
+
+        // A following small ゃ/ゅ/ょ palatalizes an i-row syllable into a digraph.
+        if vowel == Vowel::I {
+            if let Some(&next_raw) = chars.get(i + 1) {
+                let next = super::convert_katakana_to_hiragana(next_raw);
+                if is_small_y(next) {
+                    if let Some(y_vowel) = super::get_vowel_for_hiragana(next) {
+                        result.push_str(&combining_consonant(consonant));
+                        result.push(vowel_letter(y_vowel));
+                        i += 2;
+                        continue;
+                    }
+                }
+            }
+        }
+
+        result.push_str(&syllable_romaji(consonant, vowel));
+        i += 1;
+    }
+
+    // A trailing っ with nothing left to double is emitted as is, per the crate's leniency policy.
+    if pending_sokuon {
+        result.push('っ');
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("きょうだい", "kyoudai")]
+    #[case("がっこう", "gakkou")]
+    #[case("しんぶん", "shinbun")]
+    #[case("じしょ", "jisho")]
+    #[case("コーヒー", "koohii")]
+    #[case("にほん", "nihon")]
+    #[case("a", "a")]
+    #[case("わたし", "watashi")]
+    #[case("かわ", "kawa")]
+    #[case("を", "wo")]
+    #[case("あっ", "aっ")]
+    #[case("んっ", "nっ")]
+    fn to_romaji_test(#[case] kana: &str, #[case] expected: &str) {
+        assert_eq!(expected, to_romaji(kana));
+    }
+
+    #[rstest]
+    #[case("kyoudai", "きょうだい")]
+    #[case("gakkou", "がっこう")]
+    #[case("shinbun", "しんぶん")]
+    #[case("jisho", "じしょ")]
+    #[case("nihon", "にほん")]
+    #[case("nna", "んな")]
+    #[case("xyz", "xyz")]
+    #[case("wa", "わ")]
+    #[case("wo", "を")]
+    fn to_kana_test(#[case] romaji: &str, #[case] expected: &str) {
+        assert_eq!(expected, to_kana(romaji));
+    }
+}
@@ -0,0 +1,166 @@
+//! Folds half-width/full-width variants (the FF00–FFEF block) to their canonical counterparts.
+//!
+//! This is the standard NFKC-style width normalization typically needed before downstream text
+//! matching: half-width katakana fold to full-width katakana, full-width roman folds to ASCII,
+//! and the ideographic space folds to a regular space.
+
+use maplit::hashmap;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::charset;
+
+const FULL_WIDTH_ROMAN_OFFSET: u32 = 0xfee0;
+const HALF_WIDTH_DAKUTEN: char = '\u{ff9e}';
+const HALF_WIDTH_HANDAKUTEN: char = '\u{ff9f}';
+
+static HALF_TO_FULL_KATAKANA: Lazy<HashMap<char, char>> = Lazy::new(|| {
+    hashmap! {
+        '｡' => '。', '｢' => '「', '｣' => '」', '､' => '、', '･' => '・',
+        'ｦ' => 'ヲ', 'ｧ' => 'ァ', 'ｨ' => 'ィ', 'ｩ' => 'ゥ', 'ｪ' => 'ェ',
+        'ｫ' => 'ォ', 'ｬ' => 'ャ', 'ｭ' => 'ュ', 'ｮ' => 'ョ', 'ｯ' => 'ッ',
+        'ｰ' => 'ー', 'ｱ' => 'ア', 'ｲ' => 'イ', 'ｳ' => 'ウ', 'ｴ' => 'エ',
+        'ｵ' => 'オ', 'ｶ' => 'カ', 'ｷ' => 'キ', 'ｸ' => 'ク', 'ｹ' => 'ケ',
+        'ｺ' => 'コ', 'ｻ' => 'サ', 'ｼ' => 'シ', 'ｽ' => 'ス', 'ｾ' => 'セ',
+        'ｿ' => 'ソ', 'ﾀ' => 'タ', 'ﾁ' => 'チ', 'ﾂ' => 'ツ', 'ﾃ' => 'テ',
+        'ﾄ' => 'ト', 'ﾅ' => 'ナ', 'ﾆ' => 'ニ', 'ﾇ' => 'ヌ', 'ﾈ' => 'ネ',
+        'ﾉ' => 'ノ', 'ﾊ' => 'ハ', 'ﾋ' => 'ヒ', 'ﾌ' => 'フ', 'ﾍ' => 'ヘ',
+        'ﾎ' => 'ホ', 'ﾏ' => 'マ', 'ﾐ' => 'ミ', 'ﾑ' => 'ム', 'ﾒ' => 'メ',
+        'ﾓ' => 'モ', 'ﾔ' => 'ヤ', 'ﾕ' => 'ユ', 'ﾖ' => 'ヨ', 'ﾗ' => 'ラ',
+        'ﾘ' => 'リ', 'ﾙ' => 'ル', 'ﾚ' => 'レ', 'ﾛ' => 'ロ', 'ﾜ' => 'ワ',
+        'ﾝ' => 'ン',
+        '\u{ff9e}' => '゛', '\u{ff9f}' => '゜',
+    }
+});
+
+static DAKUTEN_COMBOS: Lazy<HashMap<char, char>> = Lazy::new(|| {
+    hashmap! {
+        'ｶ' => 'ガ', 'ｷ' => 'ギ', 'ｸ' => 'グ', 'ｹ' => 'ゲ', 'ｺ' => 'ゴ',
+        'ｻ' => 'ザ', 'ｼ' => 'ジ', 'ｽ' => 'ズ', 'ｾ' => 'ゼ', 'ｿ' => 'ゾ',
+        'ﾀ' => 'ダ', 'ﾁ' => 'ヂ', 'ﾂ' => 'ヅ', 'ﾃ' => 'デ', 'ﾄ' => 'ド',
+        'ﾊ' => 'バ', 'ﾋ' => 'ビ', 'ﾌ' => 'ブ', 'ﾍ' => 'ベ', 'ﾎ' => 'ボ',
+        'ｳ' => 'ヴ',
+    }
+});
+
+static HANDAKUTEN_COMBOS: Lazy<HashMap<char, char>> = Lazy::new(|| {
+    hashmap! {
+        'ﾊ' => 'パ', 'ﾋ' => 'ピ', 'ﾌ' => 'プ', 'ﾍ' => 'ペ', 'ﾎ' => 'ポ',
+    }
+});
+
+static FULL_TO_HALF_KATAKANA: Lazy<HashMap<char, char>> =
+    Lazy::new(|| HALF_TO_FULL_KATAKANA.iter().map(|(&half, &full)| (full, half)).collect());
+static FULL_TO_HALF_DAKUTEN: Lazy<HashMap<char, char>> =
+    Lazy::new(|| DAKUTEN_COMBOS.iter().map(|(&half, &full)| (full, half)).collect());
+static FULL_TO_HALF_HANDAKUTEN: Lazy<HashMap<char, char>> =
+    Lazy::new(|| HANDAKUTEN_COMBOS.iter().map(|(&half, &full)| (full, half)).collect());
+
+/// Converts the given half-width katakana string to full-width katakana.
+///
+/// A half-width kana immediately followed by the half-width dakuten `ﾞ` or handakuten `ﾟ` is
+/// combined into a single precomposed full-width katakana (e.g. `ｶ`+`ﾞ`→`ガ`, `ﾊ`+`ﾟ`→`パ`).
+pub fn convert_half_width_to_full_width_katakana_string(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut result = String::with_capacity(chars.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let ch = chars[i];
+        if !charset::is_half_width_katakana(ch) {
+            result.push(ch);
+            i += 1;
+            continue;
+        }
+
+        if let Some(&next) = chars.get(i + 1) {
+            let combined = match next {
+                HALF_WIDTH_DAKUTEN => DAKUTEN_COMBOS.get(&ch),
+                HALF_WIDTH_HANDAKUTEN => HANDAKUTEN_COMBOS.get(&ch),
+                _ => None,
+            };
+            if let Some(&combined) = combined {
+                result.push(combined);
+                i += 2;
+                continue;
+            }
+        }
+
+        result.push(HALF_TO_FULL_KATAKANA.get(&ch).copied().unwrap_or(ch));
+        i += 1;
+    }
+
+    result
+}
+
+/// Converts the given full-width katakana string to half-width katakana.
+///
+/// A precomposed voiced/semi-voiced full-width katakana (e.g. `ガ`) is split back into its base
+/// half-width kana followed by the half-width dakuten/handakuten mark (e.g. `ｶ` + `ﾞ`).
+pub fn convert_full_width_to_half_width_katakana_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+
+    for ch in s.chars() {
+        if let Some(&half) = FULL_TO_HALF_DAKUTEN.get(&ch) {
+            result.push(half);
+            result.push(HALF_WIDTH_DAKUTEN);
+        } else if let Some(&half) = FULL_TO_HALF_HANDAKUTEN.get(&ch) {
+            result.push(half);
+            result.push(HALF_WIDTH_HANDAKUTEN);
+        } else if let Some(&half) = FULL_TO_HALF_KATAKANA.get(&ch) {
+            result.push(half);
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+/// Converts the given full-width roman string to ASCII, also folding the ideographic space (3000)
+/// to a regular space.
+pub fn convert_full_width_roman_to_ascii(s: &str) -> String {
+    s.chars()
+        .map(|ch| {
+            if charset::is_ideographic_space(ch) {
+                ' '
+            } else if charset::is_full_width_roman(ch) {
+                char::from_u32(ch as u32 - FULL_WIDTH_ROMAN_OFFSET).unwrap_or(ch)
+            } else {
+                ch
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case("ｶﾞｯｺｳ", "ガッコウ")]
+    #[case("ﾊﾟﾌﾟｱ", "パプア")]
+    #[case("ｳﾞ", "ヴ")]
+    #[case("ﾞ", "゛")]
+    #[case("ABC123", "ABC123")]
+    fn convert_half_width_to_full_width_katakana_string_test(#[case] half: &str, #[case] expected: &str) {
+        assert_eq!(expected, convert_half_width_to_full_width_katakana_string(half));
+    }
+
+    #[rstest]
+    #[case("ガッコウ", "ｶﾞｯｺｳ")]
+    #[case("パプア", "ﾊﾟﾌﾟｱ")]
+    #[case("ヴ", "ｳﾞ")]
+    #[case("ABC123", "ABC123")]
+    fn convert_full_width_to_half_width_katakana_string_test(#[case] full: &str, #[case] expected: &str) {
+        assert_eq!(expected, convert_full_width_to_half_width_katakana_string(full));
+    }
+
+    #[rstest]
+    #[case("Ｈｅｌｌｏ　Ｗｏｒｌｄ！", "Hello World!")]
+    #[case("123", "123")]
+    fn convert_full_width_roman_to_ascii_test(#[case] full_width: &str, #[case] expected: &str) {
+        assert_eq!(expected, convert_full_width_roman_to_ascii(full_width));
+    }
+}